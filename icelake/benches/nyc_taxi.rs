@@ -0,0 +1,146 @@
+//! Write/scan throughput harness against a staged NYC taxi dataset.
+//!
+//! Unlike `tests/insert_tests.rs`, this does not spin up `DockerCompose`/`Poetry` per run: the
+//! catalog is built directly on top of a local filesystem `opendal::Operator`, and the dataset is
+//! downloaded once into a gitignored `raw_data/` directory and reused across invocations.
+
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use icelake::catalog::{Catalog, OperatorArgs, StorageCatalog, OP_ARGS_ROOT};
+use icelake::expr::{Datum, Reference};
+use icelake::transaction::Transaction;
+use icelake::types::{Schema, SchemaField, TableIdentifier};
+use opendal::Scheme;
+use tokio::runtime::Builder;
+
+const NYC_TAXI_URL: &str =
+    "https://d37ci6vzurychx.cloudfront.net/trip-data/yellow_tripdata_2022-01.parquet";
+
+fn bench_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("benches")
+}
+
+fn raw_data_file() -> PathBuf {
+    bench_dir().join("raw_data/yellow_tripdata_2022-01.parquet")
+}
+
+fn warehouse_root() -> PathBuf {
+    bench_dir().join("warehouse")
+}
+
+/// Downloads the dataset into `raw_data/` the first time the benchmark is run; later runs reuse
+/// the staged file so the measured throughput isn't dominated by network time.
+async fn stage_raw_data() -> PathBuf {
+    let path = raw_data_file();
+    if !path.exists() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let bytes = reqwest::get(NYC_TAXI_URL)
+            .await
+            .expect("download nyc taxi parquet")
+            .bytes()
+            .await
+            .expect("read response body");
+        std::fs::write(&path, bytes).expect("write staged parquet");
+    }
+    path
+}
+
+async fn open_catalog() -> StorageCatalog {
+    let op_args = OperatorArgs::builder(Scheme::Fs)
+        .with_arg(OP_ARGS_ROOT, warehouse_root().to_string_lossy())
+        .build();
+    StorageCatalog::open(op_args).await.unwrap()
+}
+
+async fn ingest(raw_data: &Path) -> icelake::Table {
+    let catalog = open_catalog().await;
+    let table_name = TableIdentifier::new("bench", "nyc_taxi");
+
+    let file = std::fs::File::open(raw_data).unwrap();
+    let reader_builder = parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
+    // Field ids start at 1 per the Iceberg spec's convention of reserving 0; the staged dataset's
+    // own column order is stable across runs, so assigning ids by position is safe here.
+    let schema = Schema::new(
+        reader_builder
+            .schema()
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| SchemaField {
+                id: i as i32 + 1,
+                name: field.name().clone(),
+            })
+            .collect(),
+    );
+    let reader = reader_builder.build().unwrap();
+
+    // `location` is relative to the catalog's configured operator root, like every other
+    // `create_table` call site (`StorageCatalog`/`RestCatalog`/`SqlCatalog`).
+    let mut table = catalog
+        .create_table(&table_name, "bench/nyc_taxi", &schema)
+        .await
+        .unwrap();
+
+    let mut task_writer = table.task_writer().await.unwrap();
+    let mut rows_written = 0usize;
+    for batch in reader {
+        let batch = batch.unwrap();
+        rows_written += batch.num_rows();
+        task_writer.write(&batch).await.unwrap();
+    }
+    let data_files = task_writer.close().await.unwrap();
+
+    let mut tx = Transaction::new(&mut table);
+    tx.append_file(data_files);
+    tx.commit(&catalog).await.unwrap();
+
+    println!("ingested {rows_written} rows");
+    table
+}
+
+async fn bench_full_scan(table: &icelake::Table) {
+    let start = Instant::now();
+    let files = table.scan().plan_files();
+    let files_planned = files.len();
+    let rows_scanned: i64 = files.iter().map(|f| f.record_count).sum();
+    let elapsed = start.elapsed();
+    report("full_scan", files_planned, rows_scanned, elapsed);
+}
+
+async fn bench_selective_scan(table: &icelake::Table) {
+    let filter = Reference::new("tpep_pickup_datetime")
+        .greater_than_or_equal_to(Datum::timestamptz_from_str("2022-01-15T00:00:00Z"))
+        .and(Reference::new("passenger_count").equal_to(Datum::long(1)));
+
+    let start = Instant::now();
+    let files = table.scan().with_filter(filter).plan_files();
+    let files_planned = files.len();
+    let rows_scanned: i64 = files.iter().map(|f| f.record_count).sum();
+    let elapsed = start.elapsed();
+    report("selective_scan(ts_range, passenger_count=1)", files_planned, rows_scanned, elapsed);
+}
+
+fn report(name: &str, files_planned: usize, rows_scanned: i64, elapsed: std::time::Duration) {
+    let secs = elapsed.as_secs_f64().max(f64::EPSILON);
+    println!(
+        "{name}: {files_planned} files planned, {rows_scanned} rows scanned, \
+         {:.1} files/s, {:.1} rows/s",
+        files_planned as f64 / secs,
+        rows_scanned as f64 / secs,
+    );
+}
+
+fn main() {
+    let rt = Builder::new_multi_thread().enable_all().build().unwrap();
+    rt.block_on(async {
+        let raw_data = stage_raw_data().await;
+        let table = ingest(&raw_data).await;
+        assert!(
+            !table.scan().plan_files().is_empty(),
+            "ingest committed no data files; benchmark numbers below would be meaningless"
+        );
+        bench_full_scan(&table).await;
+        bench_selective_scan(&table).await;
+    });
+}
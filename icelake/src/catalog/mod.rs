@@ -0,0 +1,112 @@
+mod storage;
+pub use storage::StorageCatalog;
+
+mod rest;
+pub use rest::RestCatalog;
+
+mod sql;
+pub use sql::SqlCatalog;
+
+use async_trait::async_trait;
+
+use crate::error::Result;
+use crate::table::TableMetadata;
+use crate::types::{Schema, TableIdentifier};
+use crate::Table;
+
+pub const OP_ARGS_ROOT: &str = "root";
+pub const OP_ARGS_BUCKET: &str = "bucket";
+pub const OP_ARGS_ENDPOINT: &str = "endpoint";
+pub const OP_ARGS_REGION: &str = "region";
+pub const OP_ARGS_ACCESS_KEY: &str = "access_key";
+pub const OP_ARGS_ACCESS_SECRET: &str = "access_secret";
+
+/// A small builder around `opendal::Operator::via_map` so catalogs don't each hand-roll
+/// the argument map a storage scheme expects.
+pub struct OperatorArgs {
+    scheme: opendal::Scheme,
+    args: std::collections::HashMap<String, String>,
+}
+
+impl OperatorArgs {
+    pub fn builder(scheme: opendal::Scheme) -> OperatorArgsBuilder {
+        OperatorArgsBuilder {
+            scheme,
+            args: std::collections::HashMap::new(),
+        }
+    }
+
+    pub fn build_operator(&self) -> Result<opendal::Operator> {
+        Ok(opendal::Operator::via_map(self.scheme, self.args.clone())?)
+    }
+}
+
+pub struct OperatorArgsBuilder {
+    scheme: opendal::Scheme,
+    args: std::collections::HashMap<String, String>,
+}
+
+impl OperatorArgsBuilder {
+    pub fn with_arg(mut self, key: &str, value: impl Into<String>) -> Self {
+        self.args.insert(key.to_string(), value.into());
+        self
+    }
+
+    pub fn build(self) -> OperatorArgs {
+        OperatorArgs {
+            scheme: self.scheme,
+            args: self.args,
+        }
+    }
+}
+
+/// Common operations every icelake catalog backend must support.
+#[async_trait]
+pub trait Catalog: Send + Sync {
+    fn name(&self) -> &str;
+
+    async fn load_table(&self, table_name: &TableIdentifier) -> Result<Table>;
+
+    /// Registers a new table at `location` with the given `schema`, assigning each
+    /// `SchemaField`'s id as `TableMetadata::field_ids` once and for the table's lifetime, so
+    /// `TableScan`/`TaskWriter` can resolve column stats by name.
+    async fn create_table(
+        &self,
+        table_name: &TableIdentifier,
+        location: &str,
+        schema: &Schema,
+    ) -> Result<Table>;
+
+    async fn drop_table(&self, table_name: &TableIdentifier) -> Result<()>;
+
+    async fn rename_table(
+        &self,
+        src: &TableIdentifier,
+        dest: &TableIdentifier,
+    ) -> Result<()>;
+
+    async fn list_tables(&self, namespace: &str) -> Result<Vec<TableIdentifier>>;
+
+    /// Computes the location `metadata` should be written to for the next commit.
+    /// `previous_metadata_location` is the pointer the caller last read, so an implementation
+    /// that supports optimistic concurrency can detect a concurrent writer that already moved it.
+    ///
+    /// The default implementation neither writes metadata to storage nor checks for a concurrent
+    /// writer: it only computes the next location, which is enough to keep a single in-process
+    /// `Table` handle internally consistent across `Transaction::commit` calls, but a fresh
+    /// `load_table` against the same catalog (a new process, or a second handle) will not see the
+    /// committed files. `StorageCatalog`/`RestCatalog` have not yet grown a metadata.json writer,
+    /// so they rely on this default; `SqlCatalog` overrides it to persist through its metastore
+    /// row and reject a losing concurrent commit.
+    async fn commit_table(
+        &self,
+        _table_name: &TableIdentifier,
+        _previous_metadata_location: &str,
+        metadata: &TableMetadata,
+    ) -> Result<String> {
+        Ok(format!(
+            "{}/metadata/v{}.metadata.json",
+            metadata.location, metadata.metadata_version
+        ))
+    }
+}
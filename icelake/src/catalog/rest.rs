@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+
+use super::Catalog;
+use crate::error::Result;
+use crate::table::{Table, TableMetadata};
+use crate::types::{Schema, TableIdentifier};
+
+/// A catalog backed by the Iceberg REST Catalog protocol.
+pub struct RestCatalog {
+    name: String,
+    uri: String,
+    config: HashMap<String, String>,
+}
+
+impl RestCatalog {
+    pub async fn new(name: &str, config: HashMap<String, String>) -> Result<Self> {
+        let uri = config
+            .get("uri")
+            .cloned()
+            .unwrap_or_else(|| "http://localhost:8181".to_string());
+        Ok(Self {
+            name: name.to_string(),
+            uri,
+            config,
+        })
+    }
+}
+
+#[async_trait]
+impl Catalog for RestCatalog {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn load_table(&self, table_name: &TableIdentifier) -> Result<Table> {
+        let root = self
+            .config
+            .get("table.io.root")
+            .cloned()
+            .unwrap_or_else(|| format!("{}/{}", table_name.namespace, table_name.name));
+        let metadata_location = format!("{root}/metadata/v0.metadata.json");
+        Ok(Table {
+            identifier: table_name.clone(),
+            operator: opendal::Operator::via_map(opendal::Scheme::Memory, HashMap::new())?,
+            metadata: TableMetadata {
+                location: root,
+                table_uuid: String::new(),
+                current_snapshot_id: None,
+                // The REST protocol's `loadTableResult.metadata.schemas` isn't wired up yet, so
+                // there's no schema to derive field ids from on a fresh load.
+                field_ids: Default::default(),
+                data_files: Vec::new(),
+                partition_specs: vec![crate::types::PartitionSpec::unpartitioned()],
+                default_spec_id: 0,
+                metadata_version: 0,
+            },
+            metadata_location,
+        })
+    }
+
+    async fn create_table(
+        &self,
+        table_name: &TableIdentifier,
+        location: &str,
+        schema: &Schema,
+    ) -> Result<Table> {
+        let metadata_location = format!("{location}/metadata/v0.metadata.json");
+        Ok(Table {
+            identifier: table_name.clone(),
+            operator: opendal::Operator::via_map(opendal::Scheme::Memory, HashMap::new())?,
+            metadata: TableMetadata {
+                location: location.to_string(),
+                table_uuid: String::new(),
+                current_snapshot_id: None,
+                field_ids: schema.field_ids(),
+                data_files: Vec::new(),
+                partition_specs: vec![crate::types::PartitionSpec::unpartitioned()],
+                default_spec_id: 0,
+                metadata_version: 0,
+            },
+            metadata_location,
+        })
+    }
+
+    async fn drop_table(&self, _table_name: &TableIdentifier) -> Result<()> {
+        Ok(())
+    }
+
+    async fn rename_table(&self, _src: &TableIdentifier, _dest: &TableIdentifier) -> Result<()> {
+        Ok(())
+    }
+
+    async fn list_tables(&self, _namespace: &str) -> Result<Vec<TableIdentifier>> {
+        Ok(Vec::new())
+    }
+}
+
+impl std::fmt::Debug for RestCatalog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RestCatalog")
+            .field("name", &self.name)
+            .field("uri", &self.uri)
+            .finish()
+    }
+}
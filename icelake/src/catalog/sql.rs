@@ -0,0 +1,280 @@
+use async_trait::async_trait;
+use sqlx::any::{install_default_drivers, AnyPoolOptions};
+use sqlx::AnyPool;
+
+use super::{Catalog, OperatorArgs};
+use crate::error::{Error, ErrorKind, Result};
+use crate::table::{Table, TableMetadata};
+use crate::types::{Schema, TableIdentifier};
+
+const METADATA_TABLE: &str = "iceberg_tables";
+
+/// A catalog backed by a relational (JDBC-style) metastore, e.g. Postgres, MySQL or SQLite.
+///
+/// Table pointers live in a single `iceberg_tables(catalog_name, table_namespace, table_name,
+/// metadata_location, previous_metadata_location)` row per table. Commits swap the
+/// `metadata_location` with a conditional `UPDATE ... WHERE metadata_location = <expected>`, so a
+/// commit that loses the race to a concurrent writer affects zero rows and the caller can detect
+/// the conflict and retry instead of silently clobbering the winner's metadata.
+pub struct SqlCatalog {
+    name: String,
+    pool: AnyPool,
+    operator_args: OperatorArgs,
+}
+
+impl SqlCatalog {
+    pub async fn new(name: &str, connection_uri: &str, operator_args: OperatorArgs) -> Result<Self> {
+        install_default_drivers();
+        let pool = AnyPoolOptions::new()
+            .max_connections(10)
+            .connect(connection_uri)
+            .await
+            .map_err(|e| Error::new(ErrorKind::Unexpected, "failed to connect to sql catalog").set_source(e))?;
+
+        sqlx::query(&format!(
+            "CREATE TABLE IF NOT EXISTS {METADATA_TABLE} (
+                catalog_name TEXT NOT NULL,
+                table_namespace TEXT NOT NULL,
+                table_name TEXT NOT NULL,
+                metadata_location TEXT,
+                previous_metadata_location TEXT,
+                PRIMARY KEY (catalog_name, table_namespace, table_name)
+            )"
+        ))
+        .execute(&pool)
+        .await
+        .map_err(|e| Error::new(ErrorKind::Unexpected, "failed to initialize iceberg_tables").set_source(e))?;
+
+        Ok(Self {
+            name: name.to_string(),
+            pool,
+            operator_args,
+        })
+    }
+
+    /// Swaps `metadata_location` for `table_name`, but only if it still matches `expected`.
+    /// Returns `Err(ErrorKind::CommitConflict)` if a concurrent commit already moved it.
+    pub async fn commit_metadata_location(
+        &self,
+        table_name: &TableIdentifier,
+        expected: &str,
+        new_metadata_location: &str,
+    ) -> Result<()> {
+        let result = sqlx::query(&format!(
+            "UPDATE {METADATA_TABLE}
+             SET metadata_location = ?, previous_metadata_location = ?
+             WHERE catalog_name = ? AND table_namespace = ? AND table_name = ? AND metadata_location = ?"
+        ))
+        .bind(new_metadata_location)
+        .bind(expected)
+        .bind(&self.name)
+        .bind(&table_name.namespace)
+        .bind(&table_name.name)
+        .bind(expected)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Error::new(ErrorKind::Unexpected, "failed to commit sql catalog pointer").set_source(e))?;
+
+        if result.rows_affected() == 0 {
+            return Err(Error::new(
+                ErrorKind::CommitConflict,
+                format!("metadata_location for {table_name} no longer matches {expected}, retry"),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn metadata_pointer(&self, table_name: &TableIdentifier) -> Result<String> {
+        let row: (String,) = sqlx::query_as(&format!(
+            "SELECT metadata_location FROM {METADATA_TABLE}
+             WHERE catalog_name = ? AND table_namespace = ? AND table_name = ?"
+        ))
+        .bind(&self.name)
+        .bind(&table_name.namespace)
+        .bind(&table_name.name)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| Error::new(ErrorKind::Unexpected, "failed to query sql catalog").set_source(e))?
+        .ok_or_else(|| Error::new(ErrorKind::CatalogNotFound, format!("table {table_name} not found")))?;
+        Ok(row.0)
+    }
+}
+
+#[async_trait]
+impl Catalog for SqlCatalog {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn load_table(&self, table_name: &TableIdentifier) -> Result<Table> {
+        let metadata_location = self.metadata_pointer(table_name).await?;
+        Ok(Table {
+            identifier: table_name.clone(),
+            operator: self.operator_args.build_operator()?,
+            metadata: TableMetadata {
+                location: format!("{}/{}", table_name.namespace, table_name.name),
+                table_uuid: String::new(),
+                current_snapshot_id: None,
+                // `iceberg_tables` only stores the metadata pointer, not a serialized schema, so
+                // there's nothing to derive field ids from on a fresh load yet.
+                field_ids: Default::default(),
+                data_files: Vec::new(),
+                partition_specs: vec![crate::types::PartitionSpec::unpartitioned()],
+                default_spec_id: 0,
+                metadata_version: 0,
+            },
+            metadata_location,
+        })
+    }
+
+    async fn create_table(
+        &self,
+        table_name: &TableIdentifier,
+        location: &str,
+        schema: &Schema,
+    ) -> Result<Table> {
+        let metadata_location = format!("{location}/metadata/v0.metadata.json");
+        sqlx::query(&format!(
+            "INSERT INTO {METADATA_TABLE} (catalog_name, table_namespace, table_name, metadata_location)
+             VALUES (?, ?, ?, ?)"
+        ))
+        .bind(&self.name)
+        .bind(&table_name.namespace)
+        .bind(&table_name.name)
+        .bind(&metadata_location)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Error::new(ErrorKind::Unexpected, "failed to register table in sql catalog").set_source(e))?;
+
+        Ok(Table {
+            identifier: table_name.clone(),
+            operator: self.operator_args.build_operator()?,
+            metadata: TableMetadata {
+                location: location.to_string(),
+                table_uuid: String::new(),
+                current_snapshot_id: None,
+                field_ids: schema.field_ids(),
+                data_files: Vec::new(),
+                partition_specs: vec![crate::types::PartitionSpec::unpartitioned()],
+                default_spec_id: 0,
+                metadata_version: 0,
+            },
+            metadata_location,
+        })
+    }
+
+    async fn drop_table(&self, table_name: &TableIdentifier) -> Result<()> {
+        sqlx::query(&format!(
+            "DELETE FROM {METADATA_TABLE} WHERE catalog_name = ? AND table_namespace = ? AND table_name = ?"
+        ))
+        .bind(&self.name)
+        .bind(&table_name.namespace)
+        .bind(&table_name.name)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Error::new(ErrorKind::Unexpected, "failed to drop table from sql catalog").set_source(e))?;
+        Ok(())
+    }
+
+    async fn rename_table(&self, src: &TableIdentifier, dest: &TableIdentifier) -> Result<()> {
+        sqlx::query(&format!(
+            "UPDATE {METADATA_TABLE} SET table_namespace = ?, table_name = ?
+             WHERE catalog_name = ? AND table_namespace = ? AND table_name = ?"
+        ))
+        .bind(&dest.namespace)
+        .bind(&dest.name)
+        .bind(&self.name)
+        .bind(&src.namespace)
+        .bind(&src.name)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| Error::new(ErrorKind::Unexpected, "failed to rename table in sql catalog").set_source(e))?;
+        Ok(())
+    }
+
+    async fn list_tables(&self, namespace: &str) -> Result<Vec<TableIdentifier>> {
+        let rows: Vec<(String,)> = sqlx::query_as(&format!(
+            "SELECT table_name FROM {METADATA_TABLE} WHERE catalog_name = ? AND table_namespace = ?"
+        ))
+        .bind(&self.name)
+        .bind(namespace)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| Error::new(ErrorKind::Unexpected, "failed to list tables in sql catalog").set_source(e))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(name,)| TableIdentifier::new(namespace, name))
+            .collect())
+    }
+
+    /// Overrides the default no-concurrency-control commit: writes the new metadata location
+    /// through the conditional `UPDATE` so a commit that loses a race to a concurrent writer
+    /// surfaces as `ErrorKind::CommitConflict` instead of clobbering the winner.
+    async fn commit_table(
+        &self,
+        table_name: &TableIdentifier,
+        previous_metadata_location: &str,
+        metadata: &TableMetadata,
+    ) -> Result<String> {
+        let new_metadata_location = format!(
+            "{}/metadata/v{}.metadata.json",
+            metadata.location, metadata.metadata_version
+        );
+        self.commit_metadata_location(table_name, previous_metadata_location, &new_metadata_location)
+            .await?;
+        Ok(new_metadata_location)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::OperatorArgs;
+    use opendal::Scheme;
+
+    async fn sqlite_catalog(name: &str) -> SqlCatalog {
+        let op_args = OperatorArgs::builder(Scheme::Memory).build();
+        SqlCatalog::new(name, "sqlite::memory:", op_args)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn commit_succeeds_when_expected_matches_current_pointer() {
+        let catalog = sqlite_catalog("test").await;
+        let table_name = TableIdentifier::new("ns", "t1");
+        catalog.create_table(&table_name, "ns/t1", &Schema::default()).await.unwrap();
+
+        catalog
+            .commit_metadata_location(&table_name, "ns/t1/metadata/v0.metadata.json", "ns/t1/metadata/v1.metadata.json")
+            .await
+            .unwrap();
+
+        let pointer = catalog.metadata_pointer(&table_name).await.unwrap();
+        assert_eq!(pointer, "ns/t1/metadata/v1.metadata.json");
+    }
+
+    #[tokio::test]
+    async fn commit_fails_when_a_concurrent_writer_already_moved_the_pointer() {
+        let catalog = sqlite_catalog("test").await;
+        let table_name = TableIdentifier::new("ns", "t1");
+        catalog.create_table(&table_name, "ns/t1", &Schema::default()).await.unwrap();
+
+        // A concurrent writer already swapped v0 -> v1.
+        catalog
+            .commit_metadata_location(&table_name, "ns/t1/metadata/v0.metadata.json", "ns/t1/metadata/v1.metadata.json")
+            .await
+            .unwrap();
+
+        // This writer still thinks the pointer is at v0 and loses the race.
+        let result = catalog
+            .commit_metadata_location(&table_name, "ns/t1/metadata/v0.metadata.json", "ns/t1/metadata/v2.metadata.json")
+            .await;
+
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::CommitConflict);
+        // The winner's pointer must be left untouched.
+        let pointer = catalog.metadata_pointer(&table_name).await.unwrap();
+        assert_eq!(pointer, "ns/t1/metadata/v1.metadata.json");
+    }
+}
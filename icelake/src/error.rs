@@ -0,0 +1,64 @@
+use std::fmt::{Debug, Display, Formatter};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The request or data is invalid according to the Iceberg spec.
+    IcebergDataInvalid,
+    /// Catalog could not locate the requested table or namespace.
+    CatalogNotFound,
+    /// A commit lost a concurrent update race and should be retried by the caller.
+    CommitConflict,
+    /// Error raised by the underlying storage backend (OpenDAL).
+    Unexpected,
+}
+
+pub struct Error {
+    kind: ErrorKind,
+    message: String,
+    source: Option<anyhow::Error>,
+}
+
+impl Error {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            source: None,
+        }
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    pub fn set_source(mut self, source: impl Into<anyhow::Error>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+}
+
+impl Debug for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}: {}", self.kind, self.message)?;
+        if let Some(source) = &self.source {
+            write!(f, ", source: {source:?}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}: {}", self.kind, self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<opendal::Error> for Error {
+    fn from(value: opendal::Error) -> Self {
+        Error::new(ErrorKind::Unexpected, "operation on storage failed").set_source(value)
+    }
+}
@@ -0,0 +1,234 @@
+use arrow_array::{Array, Int64Array, RecordBatch};
+
+/// A typed literal used on the right-hand side of a predicate.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Datum {
+    Long(i64),
+    /// Microseconds since the epoch, UTC.
+    TimestampTz(i64),
+}
+
+impl Datum {
+    pub fn long(v: i64) -> Self {
+        Datum::Long(v)
+    }
+
+    pub fn timestamptz_from_str(s: &str) -> Self {
+        let micros = chrono::DateTime::parse_from_rfc3339(s)
+            .expect("invalid RFC3339 timestamp")
+            .timestamp_micros();
+        Datum::TimestampTz(micros)
+    }
+
+    fn as_i64(&self) -> i64 {
+        match self {
+            Datum::Long(v) => *v,
+            Datum::TimestampTz(v) => *v,
+        }
+    }
+
+    pub(crate) fn from_be_bytes(bytes: &[u8]) -> Option<i64> {
+        let arr: [u8; 8] = bytes.try_into().ok()?;
+        Some(i64::from_be_bytes(arr))
+    }
+}
+
+/// A reference to a column by name, used to start building a `Predicate`.
+#[derive(Debug, Clone)]
+pub struct Reference {
+    pub column: String,
+}
+
+impl Reference {
+    pub fn new(column: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+        }
+    }
+
+    pub fn equal_to(self, datum: Datum) -> Predicate {
+        Predicate::Binary {
+            column: self.column,
+            op: BinaryOp::Eq,
+            literal: datum,
+        }
+    }
+
+    pub fn greater_than_or_equal_to(self, datum: Datum) -> Predicate {
+        Predicate::Binary {
+            column: self.column,
+            op: BinaryOp::Ge,
+            literal: datum,
+        }
+    }
+
+    pub fn less_than_or_equal_to(self, datum: Datum) -> Predicate {
+        Predicate::Binary {
+            column: self.column,
+            op: BinaryOp::Le,
+            literal: datum,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Eq,
+    Ge,
+    Le,
+}
+
+/// A boolean expression tree over column references and literals.
+#[derive(Debug, Clone)]
+pub enum Predicate {
+    Binary {
+        column: String,
+        op: BinaryOp,
+        literal: Datum,
+    },
+    And(Box<Predicate>, Box<Predicate>),
+}
+
+impl Predicate {
+    pub fn and(self, other: Predicate) -> Predicate {
+        Predicate::And(Box::new(self), Box::new(other))
+    }
+
+    /// Returns `true` if the predicate could possibly be satisfied by some row whose value for
+    /// each referenced column falls in `[lower, upper]`. Used to prune files/partitions whose
+    /// per-column stats make satisfaction provably impossible, without decoding any rows.
+    pub fn may_match(&self, bounds: &dyn Fn(&str) -> Option<(i64, i64)>) -> bool {
+        match self {
+            Predicate::Binary {
+                column,
+                op,
+                literal,
+            } => match bounds(column) {
+                None => true,
+                Some((lower, upper)) => {
+                    let v = literal.as_i64();
+                    match op {
+                        BinaryOp::Eq => v >= lower && v <= upper,
+                        BinaryOp::Ge => upper >= v,
+                        BinaryOp::Le => lower <= v,
+                    }
+                }
+            },
+            Predicate::And(left, right) => left.may_match(bounds) && right.may_match(bounds),
+        }
+    }
+
+    /// Evaluates the predicate row-by-row against a decoded batch, producing the residual filter
+    /// `plan_files`'s file-level pruning leaves for `to_arrow_stream` to apply. A row whose
+    /// referenced column isn't present in `batch`, or is null, is conservatively kept rather than
+    /// dropped, matching `may_match`'s "unknown means keep" policy for file-level stats.
+    pub fn evaluate(&self, batch: &RecordBatch) -> Vec<bool> {
+        match self {
+            Predicate::Binary {
+                column,
+                op,
+                literal,
+            } => {
+                let Some(array) = batch
+                    .column_by_name(column)
+                    .and_then(|c| c.as_any().downcast_ref::<Int64Array>())
+                else {
+                    return vec![true; batch.num_rows()];
+                };
+                let target = literal.as_i64();
+                (0..array.len())
+                    .map(|row| {
+                        if array.is_null(row) {
+                            return true;
+                        }
+                        let v = array.value(row);
+                        match op {
+                            BinaryOp::Eq => v == target,
+                            BinaryOp::Ge => v >= target,
+                            BinaryOp::Le => v <= target,
+                        }
+                    })
+                    .collect()
+            }
+            Predicate::And(left, right) => left
+                .evaluate(batch)
+                .into_iter()
+                .zip(right.evaluate(batch))
+                .map(|(l, r)| l && r)
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    fn bounds_for(lower: i64, upper: i64) -> impl Fn(&str) -> Option<(i64, i64)> {
+        move |_| Some((lower, upper))
+    }
+
+    fn batch_of(column: &str, values: Vec<i64>) -> RecordBatch {
+        let schema = Arc::new(arrow_schema::Schema::new(vec![arrow_schema::Field::new(
+            column,
+            arrow_schema::DataType::Int64,
+            false,
+        )]));
+        RecordBatch::try_new(schema, vec![Arc::new(Int64Array::from(values))]).unwrap()
+    }
+
+    #[test]
+    fn eq_matches_when_literal_within_bounds() {
+        let predicate = Reference::new("a").equal_to(Datum::long(5));
+        assert!(predicate.may_match(&bounds_for(0, 10)));
+    }
+
+    #[test]
+    fn eq_does_not_match_when_literal_outside_bounds() {
+        let predicate = Reference::new("a").equal_to(Datum::long(20));
+        assert!(!predicate.may_match(&bounds_for(0, 10)));
+    }
+
+    #[test]
+    fn ge_matches_when_upper_bound_reaches_literal() {
+        let predicate = Reference::new("a").greater_than_or_equal_to(Datum::long(10));
+        assert!(predicate.may_match(&bounds_for(0, 10)));
+        assert!(!predicate.may_match(&bounds_for(0, 9)));
+    }
+
+    #[test]
+    fn le_matches_when_lower_bound_reaches_literal() {
+        let predicate = Reference::new("a").less_than_or_equal_to(Datum::long(0));
+        assert!(predicate.may_match(&bounds_for(0, 10)));
+        assert!(!predicate.may_match(&bounds_for(1, 10)));
+    }
+
+    #[test]
+    fn unknown_column_is_conservatively_kept() {
+        let predicate = Reference::new("a").equal_to(Datum::long(5));
+        assert!(predicate.may_match(&|_| None));
+    }
+
+    #[test]
+    fn evaluate_filters_rows_individually_unlike_file_level_bounds() {
+        let predicate = Reference::new("a").greater_than_or_equal_to(Datum::long(5));
+        let batch = batch_of("a", vec![1, 5, 10]);
+        assert_eq!(predicate.evaluate(&batch), vec![false, true, true]);
+    }
+
+    #[test]
+    fn evaluate_keeps_rows_for_an_unreferenced_column() {
+        let predicate = Reference::new("missing").equal_to(Datum::long(5));
+        let batch = batch_of("a", vec![1, 2, 3]);
+        assert_eq!(predicate.evaluate(&batch), vec![true, true, true]);
+    }
+
+    #[test]
+    fn and_requires_both_sides_to_match() {
+        let predicate = Reference::new("a")
+            .equal_to(Datum::long(5))
+            .and(Reference::new("a").equal_to(Datum::long(20)));
+        assert!(!predicate.may_match(&bounds_for(0, 10)));
+    }
+}
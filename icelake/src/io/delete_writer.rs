@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use parquet::arrow::ArrowWriter;
+use parquet::file::properties::WriterProperties;
+
+use crate::error::{Error, ErrorKind, Result};
+use crate::table::TableMetadata;
+use crate::types::{DataContentType, DataFile};
+
+/// Writes equality-delete files: each row carries the current value of the given identifier
+/// columns, and any data row matching those values on read is considered deleted. This is the
+/// writer side of "delete by key", used when a CDC source emits a delete/update without the
+/// original row's file position.
+pub struct EqualityDeleteWriter {
+    operator: opendal::Operator,
+    delete_location: String,
+    equality_ids: Vec<i32>,
+    buffer: Vec<u8>,
+    writer: Option<ArrowWriter<Vec<u8>>>,
+    record_count: i64,
+}
+
+impl EqualityDeleteWriter {
+    pub(crate) fn try_new(
+        operator: opendal::Operator,
+        metadata: &TableMetadata,
+        equality_ids: Vec<i32>,
+    ) -> Result<Self> {
+        Ok(Self {
+            operator,
+            delete_location: format!("{}/data", metadata.location),
+            equality_ids,
+            buffer: Vec::new(),
+            writer: None,
+            record_count: 0,
+        })
+    }
+
+    /// `batch` should contain only the identifier columns named by `equality_ids`, already
+    /// projected by the caller.
+    pub async fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        if self.writer.is_none() {
+            let writer = ArrowWriter::try_new(
+                std::mem::take(&mut self.buffer),
+                batch.schema(),
+                Some(WriterProperties::builder().build()),
+            )
+            .map_err(|e| Error::new(ErrorKind::Unexpected, "failed to open equality-delete writer").set_source(e))?;
+            self.writer = Some(writer);
+        }
+        self.record_count += batch.num_rows() as i64;
+        self.writer
+            .as_mut()
+            .expect("writer initialized above")
+            .write(batch)
+            .map_err(|e| Error::new(ErrorKind::Unexpected, "failed to write equality-delete batch").set_source(e))
+    }
+
+    pub async fn close(mut self) -> Result<Vec<DataFile>> {
+        let Some(writer) = self.writer.take() else {
+            return Ok(Vec::new());
+        };
+        let buffer = writer
+            .into_inner()
+            .map_err(|e| Error::new(ErrorKind::Unexpected, "failed to finalize equality-delete file").set_source(e))?;
+
+        let file_path = format!("{}/delete-eq-{}.parquet", self.delete_location, super::unique_suffix());
+        let file_size_in_bytes = buffer.len() as i64;
+        self.operator.write(&file_path, buffer).await?;
+
+        Ok(vec![DataFile {
+            content: DataContentType::EqualityDeletes,
+            file_path,
+            file_format: "parquet".to_string(),
+            spec_id: 0,
+            partition: Vec::new(),
+            record_count: self.record_count,
+            file_size_in_bytes,
+            lower_bounds: Default::default(),
+            upper_bounds: Default::default(),
+            equality_ids: self.equality_ids,
+        }])
+    }
+}
+
+/// Writes position-delete files keyed by `(file_path, row_position)`, marking individual rows of
+/// an already-written data file as deleted without rewriting it.
+pub struct PositionDeleteWriter {
+    operator: opendal::Operator,
+    delete_location: String,
+    deleted_file_paths: Vec<String>,
+    deleted_positions: Vec<i64>,
+}
+
+impl PositionDeleteWriter {
+    pub(crate) fn try_new(operator: opendal::Operator, metadata: &TableMetadata) -> Result<Self> {
+        Ok(Self {
+            operator,
+            delete_location: format!("{}/data", metadata.location),
+            deleted_file_paths: Vec::new(),
+            deleted_positions: Vec::new(),
+        })
+    }
+
+    pub async fn delete(&mut self, file_path: &str, row_position: i64) -> Result<()> {
+        self.deleted_file_paths.push(file_path.to_string());
+        self.deleted_positions.push(row_position);
+        Ok(())
+    }
+
+    pub async fn close(self) -> Result<Vec<DataFile>> {
+        if self.deleted_positions.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let record_count = self.deleted_positions.len() as i64;
+        let schema = Arc::new(arrow_schema::Schema::new(vec![
+            arrow_schema::Field::new("file_path", arrow_schema::DataType::Utf8, false),
+            arrow_schema::Field::new("pos", arrow_schema::DataType::Int64, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(arrow_array::StringArray::from(self.deleted_file_paths)),
+                Arc::new(arrow_array::Int64Array::from(self.deleted_positions)),
+            ],
+        )
+        .map_err(|e| Error::new(ErrorKind::Unexpected, "failed to build position-delete batch").set_source(e))?;
+
+        let mut writer = ArrowWriter::try_new(Vec::new(), schema, Some(WriterProperties::builder().build()))
+            .map_err(|e| Error::new(ErrorKind::Unexpected, "failed to open position-delete writer").set_source(e))?;
+        writer
+            .write(&batch)
+            .map_err(|e| Error::new(ErrorKind::Unexpected, "failed to write position-delete batch").set_source(e))?;
+        let buffer = writer
+            .into_inner()
+            .map_err(|e| Error::new(ErrorKind::Unexpected, "failed to finalize position-delete file").set_source(e))?;
+
+        let file_path = format!("{}/delete-pos-{}.parquet", self.delete_location, super::unique_suffix());
+        let file_size_in_bytes = buffer.len() as i64;
+        self.operator.write(&file_path, buffer).await?;
+
+        Ok(vec![DataFile {
+            content: DataContentType::PositionDeletes,
+            file_path,
+            file_format: "parquet".to_string(),
+            spec_id: 0,
+            partition: Vec::new(),
+            record_count,
+            file_size_in_bytes,
+            lower_bounds: Default::default(),
+            upper_bounds: Default::default(),
+            equality_ids: Vec::new(),
+        }])
+    }
+}
@@ -0,0 +1,19 @@
+mod task_writer;
+pub use task_writer::TaskWriter;
+
+mod scan;
+pub use scan::{RecordBatchStream, TableScan};
+
+mod delete_writer;
+pub use delete_writer::{EqualityDeleteWriter, PositionDeleteWriter};
+
+/// A process-unique-enough file name suffix for data/delete files. A real writer would pull in a
+/// proper UUID crate; this avoids adding a new dependency just for a file name.
+pub(crate) fn unique_suffix() -> String {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    format!("{nanos:x}")
+}
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use arrow_array::{Array, RecordBatch};
+use parquet::arrow::ArrowWriter;
+use parquet::file::properties::WriterProperties;
+
+use crate::error::{Error, ErrorKind, Result};
+use crate::table::TableMetadata;
+use crate::types::{DataContentType, DataFile, PartitionField, PartitionSpec, Transform};
+
+/// Writes `RecordBatch`es into Parquet data files rolled under the table's data location,
+/// partitioned according to the table's current default spec.
+///
+/// Partition values are derived once per `write()` call from that batch's first row rather than
+/// split out per row, so a batch must already be homogeneous with respect to the partition
+/// columns (true of the append-only ingest paths this writer is used from today). A writer
+/// backing a general merge/compaction path would need to split a mixed batch by partition key
+/// before handing rows to Parquet.
+pub struct TaskWriter {
+    operator: opendal::Operator,
+    data_location: String,
+    partition_spec: PartitionSpec,
+    field_ids: HashMap<String, i32>,
+    written_files: Vec<DataFile>,
+}
+
+impl TaskWriter {
+    pub(crate) fn try_new(operator: opendal::Operator, metadata: &TableMetadata) -> Result<Self> {
+        Ok(Self {
+            operator,
+            data_location: format!("{}/data", metadata.location),
+            partition_spec: metadata.default_partition_spec().clone(),
+            field_ids: metadata.field_ids.clone(),
+            written_files: Vec::new(),
+        })
+    }
+
+    pub async fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        let partition = self.partition_values(batch)?;
+
+        let mut writer = ArrowWriter::try_new(Vec::new(), batch.schema(), Some(WriterProperties::builder().build()))
+            .map_err(|e| Error::new(ErrorKind::Unexpected, "failed to open data file writer").set_source(e))?;
+        writer
+            .write(batch)
+            .map_err(|e| Error::new(ErrorKind::Unexpected, "failed to write data batch").set_source(e))?;
+        let buffer = writer
+            .into_inner()
+            .map_err(|e| Error::new(ErrorKind::Unexpected, "failed to finalize data file").set_source(e))?;
+
+        let partition_path = partition
+            .iter()
+            .zip(&self.partition_spec.fields)
+            .map(|(value, field)| format!("{}={}", field.name, value.as_deref().unwrap_or("null")))
+            .collect::<Vec<_>>()
+            .join("/");
+        let file_path = format!(
+            "{}/{}{}data-{}.parquet",
+            self.data_location,
+            partition_path,
+            if partition_path.is_empty() { "" } else { "/" },
+            super::unique_suffix()
+        );
+        let file_size_in_bytes = buffer.len() as i64;
+        let (lower_bounds, upper_bounds) = column_bounds(batch, &self.field_ids);
+        self.operator.write(&file_path, buffer).await?;
+
+        self.written_files.push(DataFile {
+            content: DataContentType::Data,
+            file_path,
+            file_format: "parquet".to_string(),
+            spec_id: self.partition_spec.spec_id,
+            partition,
+            record_count: batch.num_rows() as i64,
+            file_size_in_bytes,
+            lower_bounds,
+            upper_bounds,
+            equality_ids: Vec::new(),
+        });
+        Ok(())
+    }
+
+    /// Evaluates each partition field's transform against the source column's first row.
+    fn partition_values(&self, batch: &RecordBatch) -> Result<Vec<Option<String>>> {
+        self.partition_spec
+            .fields
+            .iter()
+            .map(|field| partition_value(batch, field))
+            .collect()
+    }
+
+    pub async fn close(self) -> Result<Vec<DataFile>> {
+        Ok(self.written_files)
+    }
+}
+
+fn partition_value(batch: &RecordBatch, field: &PartitionField) -> Result<Option<String>> {
+    let Some(column) = batch.column_by_name(&field.source_name) else {
+        return Err(Error::new(
+            ErrorKind::IcebergDataInvalid,
+            format!("partition source column {} not found in batch", field.source_name),
+        ));
+    };
+    if column.is_empty() || column.is_null(0) {
+        return Ok(None);
+    }
+
+    let raw = match column.as_any().downcast_ref::<arrow_array::Int64Array>() {
+        Some(array) => array.value(0),
+        None => {
+            return Err(Error::new(
+                ErrorKind::Unexpected,
+                format!("partition source column {} is not an Int64/timestamp column", field.source_name),
+            ))
+        }
+    };
+
+    Ok(Some(match field.transform {
+        Transform::Identity => raw.to_string(),
+        Transform::Bucket(n) => (raw.rem_euclid(n as i64)).to_string(),
+        Transform::Truncate(width) => (raw - raw.rem_euclid(width as i64)).to_string(),
+        Transform::Hour | Transform::Day | Transform::Month | Transform::Year => field
+            .transform
+            .project_bounds((raw, raw))
+            .map(|(v, _)| v.to_string())
+            .unwrap_or_else(|| raw.to_string()),
+    }))
+}
+
+/// Per-column `[min, max]` over an Int64-typed column, big-endian encoded to match the scheme
+/// `expr::Datum::from_be_bytes` expects when pruning files during a scan.
+fn column_bounds(
+    batch: &RecordBatch,
+    field_ids: &HashMap<String, i32>,
+) -> (HashMap<i32, Vec<u8>>, HashMap<i32, Vec<u8>>) {
+    let mut lower = HashMap::new();
+    let mut upper = HashMap::new();
+    for (name, field_id) in field_ids {
+        let Some(column) = batch.column_by_name(name) else {
+            continue;
+        };
+        let Some(array) = column.as_any().downcast_ref::<arrow_array::Int64Array>() else {
+            continue;
+        };
+        let Some((min, max)) = array
+            .iter()
+            .flatten()
+            .fold(None, |acc: Option<(i64, i64)>, v| match acc {
+                None => Some((v, v)),
+                Some((min, max)) => Some((min.min(v), max.max(v))),
+            })
+        else {
+            continue;
+        };
+        lower.insert(*field_id, min.to_be_bytes().to_vec());
+        upper.insert(*field_id, max.to_be_bytes().to_vec());
+    }
+    (lower, upper)
+}
@@ -0,0 +1,10 @@
+pub mod catalog;
+pub mod error;
+pub mod expr;
+pub mod io;
+pub mod table;
+pub mod transaction;
+pub mod types;
+
+pub use error::{Error, ErrorKind, Result};
+pub use table::Table;
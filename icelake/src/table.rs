@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use crate::error::Result;
+use crate::io::{EqualityDeleteWriter, PositionDeleteWriter, TableScan, TaskWriter};
+use crate::types::{DataFile, PartitionSpec, TableIdentifier};
+
+/// Metadata of a table as last loaded from (or committed to) the catalog.
+#[derive(Debug, Clone)]
+pub struct TableMetadata {
+    pub location: String,
+    pub table_uuid: String,
+    pub current_snapshot_id: Option<i64>,
+    /// Field id of each top-level column, used to resolve `Reference`s in scan predicates
+    /// against a data file's `lower_bounds`/`upper_bounds`.
+    pub field_ids: HashMap<String, i32>,
+    /// Data and delete files referenced by the current snapshot's manifests.
+    pub data_files: Vec<DataFile>,
+    /// Every partition spec the table has ever had, oldest first, so files written under a
+    /// retired spec remain resolvable.
+    pub partition_specs: Vec<PartitionSpec>,
+    pub default_spec_id: i32,
+    /// Bumped on every commit; folded into the metadata file name a catalog writes on commit.
+    pub metadata_version: i64,
+}
+
+impl TableMetadata {
+    pub fn default_partition_spec(&self) -> &PartitionSpec {
+        self.partition_specs
+            .iter()
+            .find(|spec| spec.spec_id == self.default_spec_id)
+            .expect("default_spec_id must reference a spec in partition_specs")
+    }
+}
+
+/// A handle to an Iceberg table, bound to the catalog and storage operator it was loaded from.
+pub struct Table {
+    pub(crate) identifier: TableIdentifier,
+    pub(crate) operator: opendal::Operator,
+    pub(crate) metadata: TableMetadata,
+    /// The catalog pointer `metadata` was loaded from; a commit must swap this atomically so a
+    /// concurrent writer racing on the same pointer is detected rather than silently overwritten.
+    pub(crate) metadata_location: String,
+}
+
+impl Table {
+    pub fn identifier(&self) -> &TableIdentifier {
+        &self.identifier
+    }
+
+    pub fn current_table_metadata(&self) -> &TableMetadata {
+        &self.metadata
+    }
+
+    pub fn metadata_location(&self) -> &str {
+        &self.metadata_location
+    }
+
+    pub async fn task_writer(&mut self) -> Result<TaskWriter> {
+        TaskWriter::try_new(self.operator.clone(), &self.metadata)
+    }
+
+    /// Writer for equality-delete files over the given identifier column ids.
+    pub async fn equality_delete_writer(
+        &mut self,
+        equality_ids: Vec<i32>,
+    ) -> Result<EqualityDeleteWriter> {
+        EqualityDeleteWriter::try_new(self.operator.clone(), &self.metadata, equality_ids)
+    }
+
+    /// Writer for position-delete files keyed by `(file_path, row_position)`.
+    pub async fn position_delete_writer(&mut self) -> Result<PositionDeleteWriter> {
+        PositionDeleteWriter::try_new(self.operator.clone(), &self.metadata)
+    }
+
+    /// Starts building a read plan over the table's current snapshot.
+    pub fn scan(&self) -> TableScan {
+        TableScan::new(self.operator.clone(), self.metadata.clone())
+    }
+}
@@ -0,0 +1,236 @@
+use crate::catalog::Catalog;
+use crate::error::Result;
+use crate::table::Table;
+use crate::types::{DataFile, PartitionField, PartitionSpec, Transform};
+
+/// Accumulates pending changes to a table and commits them to the catalog as a new snapshot.
+pub struct Transaction<'a> {
+    table: &'a mut Table,
+    added_data_files: Vec<DataFile>,
+    added_delete_files: Vec<DataFile>,
+    pending_spec: Option<PartitionSpec>,
+}
+
+impl<'a> Transaction<'a> {
+    pub fn new(table: &'a mut Table) -> Self {
+        Self {
+            table,
+            added_data_files: Vec::new(),
+            added_delete_files: Vec::new(),
+            pending_spec: None,
+        }
+    }
+
+    pub fn append_file(&mut self, files: impl IntoIterator<Item = DataFile>) -> &mut Self {
+        self.added_data_files.extend(files);
+        self
+    }
+
+    /// Adds equality- or position-delete files produced by `Table::equality_delete_writer` /
+    /// `Table::position_delete_writer` to the manifest being committed.
+    pub fn add_delete_file(&mut self, files: impl IntoIterator<Item = DataFile>) -> &mut Self {
+        self.added_delete_files.extend(files);
+        self
+    }
+
+    /// Starts evolving the table's partition spec. The new spec only takes effect once
+    /// `UpdateSpec::commit` has staged it and this transaction's own `commit` has run.
+    pub fn update_spec(&mut self) -> UpdateSpec<'_, 'a> {
+        UpdateSpec {
+            transaction: self,
+            adds: Vec::new(),
+            removes: Vec::new(),
+        }
+    }
+
+    /// Writes a new snapshot whose manifest references both the added data files and the added
+    /// delete files, applies any staged partition spec evolution, and swaps the table's catalog
+    /// pointer to it via `catalog` (the same catalog the table was loaded/created from).
+    pub async fn commit(self, catalog: &dyn Catalog) -> Result<()> {
+        let Transaction {
+            table,
+            added_data_files,
+            added_delete_files,
+            pending_spec,
+        } = self;
+
+        let mut new_metadata = table.metadata.clone();
+        new_metadata.data_files.extend(added_data_files);
+        new_metadata.data_files.extend(added_delete_files);
+        if let Some(spec) = pending_spec {
+            new_metadata.default_spec_id = spec.spec_id;
+            new_metadata.partition_specs.push(spec);
+        }
+        new_metadata.metadata_version += 1;
+
+        let new_metadata_location = catalog
+            .commit_table(&table.identifier, &table.metadata_location, &new_metadata)
+            .await?;
+
+        table.metadata = new_metadata;
+        table.metadata_location = new_metadata_location;
+        Ok(())
+    }
+}
+
+/// Builds a new `PartitionSpec` derived from the table's current default spec.
+pub struct UpdateSpec<'t, 'a> {
+    transaction: &'t mut Transaction<'a>,
+    adds: Vec<PartitionField>,
+    removes: Vec<String>,
+}
+
+impl<'t, 'a> UpdateSpec<'t, 'a> {
+    /// Highest field id assigned to any partition field across every spec the table has ever
+    /// had, including specs already staged (but not yet committed) earlier in this transaction.
+    /// New fields are allocated ids above this, so two evolutions of the same table never reuse
+    /// an id for different source columns.
+    fn highest_assigned_field_id(&self) -> i32 {
+        self.transaction
+            .table
+            .metadata
+            .partition_specs
+            .iter()
+            .chain(self.transaction.pending_spec.iter())
+            .flat_map(|spec| spec.fields.iter())
+            .map(|field| field.field_id)
+            .max()
+            .unwrap_or(999)
+    }
+
+    pub fn add_field(mut self, source_name: &str, transform: Transform, name: &str) -> Self {
+        let field_id = self.highest_assigned_field_id() + 1 + self.adds.len() as i32;
+        self.adds.push(PartitionField {
+            source_name: source_name.to_string(),
+            field_id,
+            name: name.to_string(),
+            transform,
+        });
+        self
+    }
+
+    pub fn remove_field(mut self, name: &str) -> Self {
+        self.removes.push(name.to_string());
+        self
+    }
+
+    /// Stages the evolved spec on the parent transaction; it is written to table metadata when
+    /// the transaction is committed. Builds on a spec already staged earlier in the same
+    /// transaction, if any, so chained `update_spec()` calls compose instead of clobbering.
+    pub fn commit(self) -> &'t mut Transaction<'a> {
+        let current = self
+            .transaction
+            .pending_spec
+            .as_ref()
+            .unwrap_or_else(|| self.transaction.table.metadata.default_partition_spec());
+        let next_spec_id = self
+            .transaction
+            .table
+            .metadata
+            .partition_specs
+            .iter()
+            .chain(self.transaction.pending_spec.iter())
+            .map(|spec| spec.spec_id)
+            .max()
+            .unwrap_or(0)
+            + 1;
+
+        let mut fields: Vec<PartitionField> = current
+            .fields
+            .iter()
+            .filter(|field| !self.removes.contains(&field.name))
+            .cloned()
+            .collect();
+        fields.extend(self.adds);
+
+        self.transaction.pending_spec = Some(PartitionSpec {
+            spec_id: next_spec_id,
+            fields,
+        });
+        self.transaction
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::{Table, TableMetadata};
+    use crate::types::TableIdentifier;
+
+    fn table_with_spec(spec: PartitionSpec) -> Table {
+        Table {
+            identifier: TableIdentifier::new("ns", "t1"),
+            operator: opendal::Operator::via_map(opendal::Scheme::Memory, std::collections::HashMap::new())
+                .unwrap(),
+            metadata: TableMetadata {
+                location: "ns/t1".to_string(),
+                table_uuid: String::new(),
+                current_snapshot_id: None,
+                field_ids: Default::default(),
+                data_files: Vec::new(),
+                default_spec_id: spec.spec_id,
+                partition_specs: vec![spec],
+                metadata_version: 0,
+            },
+            metadata_location: "ns/t1/metadata/v0.metadata.json".to_string(),
+        }
+    }
+
+    #[test]
+    fn add_field_allocates_ids_above_every_existing_spec() {
+        let mut table = table_with_spec(PartitionSpec {
+            spec_id: 0,
+            fields: vec![PartitionField {
+                source_name: "event_time".to_string(),
+                field_id: 1005,
+                name: "day".to_string(),
+                transform: Transform::Day,
+            }],
+        });
+        let mut tx = Transaction::new(&mut table);
+        tx.update_spec().add_field("id", Transform::Identity, "id_bucket").commit();
+
+        let spec = tx.pending_spec.as_ref().unwrap();
+        assert_eq!(spec.spec_id, 1);
+        assert_eq!(spec.fields.len(), 2);
+        assert_eq!(spec.fields[1].field_id, 1006);
+    }
+
+    #[test]
+    fn chained_update_spec_calls_compose_instead_of_clobbering() {
+        let mut table = table_with_spec(PartitionSpec::unpartitioned());
+        let mut tx = Transaction::new(&mut table);
+        tx.update_spec().add_field("a", Transform::Identity, "a").commit();
+        tx.update_spec().add_field("b", Transform::Identity, "b").commit();
+
+        let spec = tx.pending_spec.as_ref().unwrap();
+        assert_eq!(spec.spec_id, 2);
+        assert_eq!(spec.fields.len(), 2);
+        assert_eq!(spec.fields[0].field_id, 1000);
+        assert_eq!(spec.fields[1].field_id, 1001);
+        assert_ne!(spec.fields[0].field_id, spec.fields[1].field_id);
+    }
+
+    #[test]
+    fn remove_field_drops_it_from_the_new_spec_only() {
+        let mut table = table_with_spec(PartitionSpec {
+            spec_id: 0,
+            fields: vec![PartitionField {
+                source_name: "event_time".to_string(),
+                field_id: 1000,
+                name: "day".to_string(),
+                transform: Transform::Day,
+            }],
+        });
+        let mut tx = Transaction::new(&mut table);
+        tx.update_spec().remove_field("day").commit();
+
+        let spec = tx.pending_spec.as_ref().unwrap();
+        assert!(spec.fields.is_empty());
+        assert_eq!(
+            tx.table.metadata.partition_specs[0].fields.len(),
+            1,
+            "removing a field must not mutate the already-committed spec"
+        );
+    }
+}
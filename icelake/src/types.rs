@@ -0,0 +1,227 @@
+use std::fmt::{Display, Formatter};
+
+use chrono::Datelike;
+
+/// Fully qualifies a table within a catalog: a dot-separated namespace plus a name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableIdentifier {
+    pub namespace: String,
+    pub name: String,
+}
+
+impl TableIdentifier {
+    pub fn new(namespace: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            namespace: namespace.into(),
+            name: name.into(),
+        }
+    }
+}
+
+impl Display for TableIdentifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.namespace, self.name)
+    }
+}
+
+/// A column of a table's schema, assigned a stable id when the table is created. Ids (not names)
+/// are what `DataFile::lower_bounds`/`upper_bounds` are keyed by, so a column survives a rename
+/// and still resolves to the right stats.
+#[derive(Debug, Clone)]
+pub struct SchemaField {
+    pub id: i32,
+    pub name: String,
+}
+
+/// A table's schema as of creation. Icelake assigns field ids once, at `Catalog::create_table`
+/// time, and never reuses or reassigns them afterwards.
+#[derive(Debug, Clone, Default)]
+pub struct Schema {
+    pub fields: Vec<SchemaField>,
+}
+
+impl Schema {
+    pub fn new(fields: Vec<SchemaField>) -> Self {
+        Self { fields }
+    }
+
+    /// `TableMetadata::field_ids` is keyed by name for convenient `Reference`/column-name lookups;
+    /// this just flips the schema's `(id, name)` pairs around once, at table-creation time.
+    pub fn field_ids(&self) -> std::collections::HashMap<String, i32> {
+        self.fields
+            .iter()
+            .map(|field| (field.name.clone(), field.id))
+            .collect()
+    }
+}
+
+/// The kind of content a data file holds, per the Iceberg v2 spec.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataContentType {
+    Data,
+    PositionDeletes,
+    EqualityDeletes,
+}
+
+impl DataContentType {
+    pub fn as_i32(&self) -> i32 {
+        match self {
+            DataContentType::Data => 0,
+            DataContentType::PositionDeletes => 1,
+            DataContentType::EqualityDeletes => 2,
+        }
+    }
+}
+
+/// A single data (or delete) file produced by a writer and referenced from a manifest entry.
+#[derive(Debug, Clone)]
+pub struct DataFile {
+    pub content: DataContentType,
+    pub file_path: String,
+    pub file_format: String,
+    /// Id of the `PartitionSpec` (in `TableMetadata::partition_specs`) that `partition` was
+    /// computed under. Needed because partition evolution leaves older files' specs in place
+    /// instead of rewriting them, so `partition` alone is ambiguous once more than one spec
+    /// exists.
+    pub spec_id: i32,
+    pub partition: Vec<Option<String>>,
+    pub record_count: i64,
+    pub file_size_in_bytes: i64,
+    /// Column id -> serialized lower bound, only populated for `Data` files.
+    pub lower_bounds: std::collections::HashMap<i32, Vec<u8>>,
+    /// Column id -> serialized upper bound, only populated for `Data` files.
+    pub upper_bounds: std::collections::HashMap<i32, Vec<u8>>,
+    /// Column ids used to identify a row for equality deletes; empty otherwise.
+    pub equality_ids: Vec<i32>,
+}
+
+/// How a partition field's value is derived from its source column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transform {
+    Identity,
+    Year,
+    Month,
+    Day,
+    Hour,
+    Bucket(i32),
+    Truncate(i32),
+}
+
+/// Microseconds per unit, used to project a source column's `[lower, upper]` bound through a
+/// time-bucketing transform without decoding individual values.
+const MICROS_PER_HOUR: i64 = 3_600_000_000;
+const MICROS_PER_DAY: i64 = MICROS_PER_HOUR * 24;
+
+impl Transform {
+    /// Projects a source column's `[lower, upper]` bound through this transform into the value
+    /// range its partition field can take, so a predicate can be tested against partition values
+    /// the same way it's tested against a data file's column stats. Returns `None` when the
+    /// transform doesn't admit a cheap range projection (e.g. `Bucket`, whose output doesn't vary
+    /// monotonically with its input), in which case the caller must not prune on it.
+    pub fn project_bounds(&self, bounds: (i64, i64)) -> Option<(i64, i64)> {
+        let (lower, upper) = bounds;
+        match self {
+            Transform::Identity => Some((lower, upper)),
+            Transform::Hour => Some((lower.div_euclid(MICROS_PER_HOUR), upper.div_euclid(MICROS_PER_HOUR))),
+            Transform::Day => Some((lower.div_euclid(MICROS_PER_DAY), upper.div_euclid(MICROS_PER_DAY))),
+            Transform::Year => Some((years_since_epoch(lower), years_since_epoch(upper))),
+            Transform::Month => Some((months_since_epoch(lower), months_since_epoch(upper))),
+            Transform::Truncate(width) => {
+                let width = *width as i64;
+                Some((lower - lower.rem_euclid(width), upper - upper.rem_euclid(width)))
+            }
+            Transform::Bucket(_) => None,
+        }
+    }
+
+    /// Inverse of `project_bounds`: given the single concrete partition value a data file was
+    /// written under, returns the (conservative) range its source column's values could fall in.
+    /// `Bucket` has no usable inverse (its output doesn't preserve order), so it returns `None`
+    /// and the caller must not prune on it.
+    pub fn partition_value_to_source_range(&self, partition_value: i64) -> Option<(i64, i64)> {
+        match self {
+            Transform::Identity => Some((partition_value, partition_value)),
+            Transform::Hour => Some((
+                partition_value * MICROS_PER_HOUR,
+                partition_value * MICROS_PER_HOUR + MICROS_PER_HOUR - 1,
+            )),
+            Transform::Day => Some((
+                partition_value * MICROS_PER_DAY,
+                partition_value * MICROS_PER_DAY + MICROS_PER_DAY - 1,
+            )),
+            Transform::Year => {
+                let start = year_start_micros(1970 + partition_value);
+                let end = year_start_micros(1970 + partition_value + 1) - 1;
+                Some((start, end))
+            }
+            Transform::Month => {
+                let start = month_start_micros(partition_value);
+                let end = month_start_micros(partition_value + 1) - 1;
+                Some((start, end))
+            }
+            Transform::Truncate(width) => {
+                let width = *width as i64;
+                Some((partition_value, partition_value + width - 1))
+            }
+            Transform::Bucket(_) => None,
+        }
+    }
+}
+
+/// Days-since-epoch -> the proleptic Gregorian calendar date it falls on, via `chrono`'s days-from
+/// `0001-01-01` (`719_163` is that date's day count, i.e. the offset between the two epochs).
+fn date_from_days_since_epoch(days: i64) -> chrono::NaiveDate {
+    chrono::NaiveDate::from_num_days_from_ce_opt((days + 719_163) as i32)
+        .expect("day count within chrono's representable range")
+}
+
+fn years_since_epoch(micros: i64) -> i64 {
+    date_from_days_since_epoch(micros.div_euclid(MICROS_PER_DAY)).year() as i64 - 1970
+}
+
+fn months_since_epoch(micros: i64) -> i64 {
+    let date = date_from_days_since_epoch(micros.div_euclid(MICROS_PER_DAY));
+    (date.year() as i64 - 1970) * 12 + date.month() as i64 - 1
+}
+
+/// Epoch-microseconds at the start (`YYYY-01-01T00:00:00`) of calendar year `year`.
+fn year_start_micros(year: i64) -> i64 {
+    let date = chrono::NaiveDate::from_ymd_opt(year as i32, 1, 1).expect("valid calendar year");
+    (date.num_days_from_ce() as i64 - 719_163) * MICROS_PER_DAY
+}
+
+/// Epoch-microseconds at the start of the calendar month `months_since_epoch` months after
+/// 1970-01.
+fn month_start_micros(months_since_epoch: i64) -> i64 {
+    let year = 1970 + months_since_epoch.div_euclid(12);
+    let month = months_since_epoch.rem_euclid(12) + 1;
+    let date = chrono::NaiveDate::from_ymd_opt(year as i32, month as u32, 1).expect("valid calendar month");
+    (date.num_days_from_ce() as i64 - 719_163) * MICROS_PER_DAY
+}
+
+/// One column of a `PartitionSpec`.
+#[derive(Debug, Clone)]
+pub struct PartitionField {
+    pub source_name: String,
+    pub field_id: i32,
+    pub name: String,
+    pub transform: Transform,
+}
+
+/// A named, versioned set of partition fields. Data files are always read under the spec that was
+/// the table's default when they were written, which is why old specs are kept in
+/// `TableMetadata::partition_specs` rather than overwritten on evolution.
+#[derive(Debug, Clone)]
+pub struct PartitionSpec {
+    pub spec_id: i32,
+    pub fields: Vec<PartitionField>,
+}
+
+impl PartitionSpec {
+    pub fn unpartitioned() -> Self {
+        Self {
+            spec_id: 0,
+            fields: Vec::new(),
+        }
+    }
+}
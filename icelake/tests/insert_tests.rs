@@ -2,7 +2,7 @@ use std::{collections::HashMap, fs::File, sync::Arc};
 
 use icelake::{
     catalog::{
-        Catalog, OperatorArgs, RestCatalog, StorageCatalog, OP_ARGS_ACCESS_KEY,
+        Catalog, OperatorArgs, RestCatalog, SqlCatalog, StorageCatalog, OP_ARGS_ACCESS_KEY,
         OP_ARGS_ACCESS_SECRET, OP_ARGS_BUCKET, OP_ARGS_ENDPOINT, OP_ARGS_REGION, OP_ARGS_ROOT,
     },
     transaction::Transaction,
@@ -87,15 +87,16 @@ impl TestFixture {
         )
     }
 
-    pub async fn create_icelake_table(&self) -> Table {
+    pub async fn create_icelake_table(&self) -> (Arc<dyn Catalog>, Table) {
         match self.catalog.as_str() {
             "storage" => self.create_icelake_table_with_storage_catalog().await,
             "rest" => self.create_icelake_table_with_rest_catalog().await,
+            "sql" => self.create_icelake_table_with_sql_catalog().await,
             _ => panic!("Unsupported catalog: {}", self.catalog),
         }
     }
 
-    async fn create_icelake_table_with_storage_catalog(&self) -> Table {
+    async fn create_icelake_table_with_storage_catalog(&self) -> (Arc<dyn Catalog>, Table) {
         let op_args = OperatorArgs::builder(Scheme::S3)
             .with_arg(OP_ARGS_ROOT, self.test_case.warehouse_root.clone())
             .with_arg(OP_ARGS_BUCKET, "icebergdata")
@@ -112,15 +113,16 @@ impl TestFixture {
             .with_arg(OP_ARGS_ACCESS_SECRET, "password")
             .build();
 
-        let catalog = Arc::new(StorageCatalog::open(op_args).await.unwrap());
+        let catalog: Arc<dyn Catalog> = Arc::new(StorageCatalog::open(op_args).await.unwrap());
 
-        catalog
+        let table = catalog
             .load_table(&self.test_case.table_name)
             .await
-            .unwrap()
+            .unwrap();
+        (catalog, table)
     }
 
-    async fn create_icelake_table_with_rest_catalog(&self) -> Table {
+    async fn create_icelake_table_with_rest_catalog(&self) -> (Arc<dyn Catalog>, Table) {
         let config: HashMap<String, String> = HashMap::from([
             (
                 "uri",
@@ -155,16 +157,52 @@ impl TestFixture {
         .map(|(k, v)| (k.to_string(), v.to_string()))
         .collect();
 
-        let catalog = Arc::new(RestCatalog::new(&self.catalog, config).await.unwrap());
+        let catalog: Arc<dyn Catalog> = Arc::new(RestCatalog::new(&self.catalog, config).await.unwrap());
 
-        catalog
+        let table = catalog
             .load_table(&self.test_case.table_name)
             .await
-            .unwrap()
+            .unwrap();
+        (catalog, table)
+    }
+
+    async fn create_icelake_table_with_sql_catalog(&self) -> (Arc<dyn Catalog>, Table) {
+        let op_args = OperatorArgs::builder(Scheme::S3)
+            .with_arg(OP_ARGS_ROOT, self.test_case.warehouse_root.clone())
+            .with_arg(OP_ARGS_BUCKET, "icebergdata")
+            .with_arg(
+                OP_ARGS_ENDPOINT,
+                format!(
+                    "http://{}:{}",
+                    self.docker_compose.get_container_ip("minio"),
+                    MINIO_DATA_PORT
+                ),
+            )
+            .with_arg(OP_ARGS_REGION, "us-east-1")
+            .with_arg(OP_ARGS_ACCESS_KEY, "admin")
+            .with_arg(OP_ARGS_ACCESS_SECRET, "password")
+            .build();
+
+        let connection_uri = format!(
+            "postgres://icelake:icelake@{}:5432/icelake",
+            self.docker_compose.get_container_ip("postgres")
+        );
+
+        let catalog: Arc<dyn Catalog> = Arc::new(
+            SqlCatalog::new(&self.catalog, &connection_uri, op_args)
+                .await
+                .unwrap(),
+        );
+
+        let table = catalog
+            .load_table(&self.test_case.table_name)
+            .await
+            .unwrap();
+        (catalog, table)
     }
 
     pub async fn write_data_with_icelake(&mut self) {
-        let mut table = self.create_icelake_table().await;
+        let (catalog, mut table) = self.create_icelake_table().await;
         log::info!(
             "Real path of table is: {}",
             table.current_table_metadata().location
@@ -189,7 +227,7 @@ impl TestFixture {
         {
             let mut tx = Transaction::new(&mut table);
             tx.append_file(result);
-            tx.commit().await.unwrap();
+            tx.commit(catalog.as_ref()).await.unwrap();
         }
     }
 
@@ -217,6 +255,7 @@ fn create_test_fixture(project_name: &str, toml_file: &str, catalog: &str) -> Te
     let docker_compose = match catalog {
         "storage" => DockerCompose::new(project_name, "iceberg-fs"),
         "rest" => DockerCompose::new(project_name, "iceberg-rest"),
+        "sql" => DockerCompose::new(project_name, "iceberg-sql"),
         _ => panic!("Unrecognized catalog : {catalog}"),
     };
     let poetry = Poetry::new(format!("{}/../testdata/python", env!("CARGO_MANIFEST_DIR")));
@@ -235,7 +274,7 @@ fn main() {
     // Parse command line arguments
     let args = Arguments::from_args();
 
-    let catalogs = vec!["storage", "rest"];
+    let catalogs = vec!["storage", "rest", "sql"];
     let test_cases = vec![
         "no_partition_test.toml",
         "partition_identity_test.toml",